@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use super::{BoxedTypeUtils, Primitive, Type};
+
+// Caps how many deref steps a single coercion search may take, so a
+// self-referential wrapper type can't send it into an infinite loop.
+const MAX_DEREF_DEPTH: usize = 8;
+
+pub enum CoercionStep {
+    Assignable,
+    NumericWiden,
+    Deref(Arc<dyn Type>),
+}
+
+// Distinct from `is_assignable_to`: tries plain assignability first, then
+// numeric widening, then `Never -> T` (itself a case of assignability), then
+// retries through a wrapper's deref target. Returns the steps taken so a
+// caller can later insert the matching conversions.
+pub fn can_coerce(from: &Arc<dyn Type>, to: &Arc<dyn Type>) -> Option<Vec<CoercionStep>> {
+    can_coerce_at(from, to, 0)
+}
+
+fn can_coerce_at(
+    from: &Arc<dyn Type>,
+    to: &Arc<dyn Type>,
+    depth: usize,
+) -> Option<Vec<CoercionStep>> {
+    if from.is_assignable_to(to.as_ref()) {
+        return Some(vec![CoercionStep::Assignable]);
+    }
+
+    if let (Some(f), Some(t)) = (
+        from.as_ref().cast::<Primitive>(),
+        to.as_ref().cast::<Primitive>(),
+    ) {
+        if numeric_widens(f, t) {
+            return Some(vec![CoercionStep::NumericWiden]);
+        }
+    }
+
+    if depth < MAX_DEREF_DEPTH {
+        if let Some(inner) = from.deref_target() {
+            if let Some(mut steps) = can_coerce_at(&inner, to, depth + 1) {
+                steps.insert(0, CoercionStep::Deref(inner));
+                return Some(steps);
+            }
+        }
+    }
+
+    None
+}
+
+fn numeric_widens(from: &Primitive, to: &Primitive) -> bool {
+    match (from, to) {
+        (Primitive::Int(n), Primitive::Int(m)) => m >= n,
+        (Primitive::UInt(n), Primitive::UInt(m)) => m >= n,
+        (Primitive::UInt(n), Primitive::Int(m)) => m > n,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::BoxableType;
+
+    #[test]
+    fn numeric_widening_succeeds_one_way() {
+        let int32 = Primitive::Int(32).arc();
+        let int64 = Primitive::Int(64).arc();
+        assert!(can_coerce(&int32, &int64).is_some());
+        assert!(can_coerce(&int64, &int32).is_none());
+    }
+
+    #[test]
+    fn uint_widens_to_uint_and_to_a_strictly_larger_int() {
+        let uint32 = Primitive::UInt(32).arc();
+        let uint64 = Primitive::UInt(64).arc();
+        let int32 = Primitive::Int(32).arc();
+        let int64 = Primitive::Int(64).arc();
+
+        assert!(can_coerce(&uint32, &uint64).is_some());
+        assert!(can_coerce(&uint32, &int64).is_some());
+        assert!(can_coerce(&uint32, &int32).is_none());
+    }
+
+    #[test]
+    fn never_coerces_to_anything_via_assignability() {
+        let never = Primitive::Never.arc();
+        let int32 = Primitive::Int(32).arc();
+        assert!(can_coerce(&never, &int32).is_some());
+    }
+
+    struct Wrapper(Arc<dyn Type>);
+    impl Type for Wrapper {
+        fn is_assignable_to(&self, _: &dyn Type) -> bool {
+            false
+        }
+        fn deref_target(&self) -> Option<Arc<dyn Type>> {
+            Some(self.0.clone())
+        }
+    }
+
+    #[test]
+    fn deref_step_retries_coercion_on_the_inner_type() {
+        let wrapped_int32 = Wrapper(Primitive::Int(32).arc()).arc();
+        let int64 = Primitive::Int(64).arc();
+
+        let steps = can_coerce(&wrapped_int32, &int64).expect("should coerce through deref");
+        assert!(steps.iter().any(|s| matches!(s, CoercionStep::Deref(_))));
+    }
+
+    struct InfiniteWrapper;
+    impl Type for InfiniteWrapper {
+        fn is_assignable_to(&self, other: &dyn Type) -> bool {
+            other.cast::<InfiniteWrapper>().is_some()
+        }
+        fn deref_target(&self) -> Option<Arc<dyn Type>> {
+            Some(InfiniteWrapper.arc())
+        }
+    }
+
+    #[test]
+    fn deref_chain_is_capped_to_avoid_infinite_loops() {
+        let from = InfiniteWrapper.arc();
+        let to = Primitive::Int(32).arc();
+        assert!(can_coerce(&from, &to).is_none());
+    }
+}
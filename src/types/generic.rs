@@ -0,0 +1,141 @@
+use std::{collections::HashMap, ptr::eq, sync::Arc};
+
+use super::{scheme::substitute, unify::TypeVarId, BoxableType, BoxedTypeUtils, Type};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Variance {
+    Covariant,
+    Contravariant,
+    Invariant,
+}
+
+// Describes a generic type constructor: its declared params' variance, and
+// a body expressed in terms of `vars`, substituted in by `subst`.
+pub struct GenericDef {
+    pub name: Box<str>,
+    pub variances: Vec<Variance>,
+    pub vars: Vec<TypeVarId>,
+    pub body: Arc<dyn Type>,
+}
+
+pub struct Generic {
+    pub base: Arc<GenericDef>,
+    pub params: Vec<Arc<dyn Type>>,
+}
+
+impl Type for Generic {
+    fn is_assignable_to(&self, other: &dyn Type) -> bool {
+        if let Some(x) = other.is_supertype_of(self.boxed()) {
+            return x;
+        }
+        if let Some(x) = other.cast::<Generic>() {
+            if !eq(self.base.as_ref(), x.base.as_ref()) || self.params.len() != x.params.len() {
+                return false;
+            }
+            self.params
+                .iter()
+                .zip(x.params.iter())
+                .zip(self.base.variances.iter())
+                .all(|((a, b), variance)| match variance {
+                    Variance::Covariant => a.is_assignable_to(b.as_ref()),
+                    Variance::Contravariant => b.is_assignable_to(a.as_ref()),
+                    Variance::Invariant => {
+                        a.is_assignable_to(b.as_ref()) && b.is_assignable_to(a.as_ref())
+                    }
+                })
+        } else {
+            false
+        }
+    }
+}
+
+// Substitutes `generic`'s params into its base's body, reusing the unifier's
+// substitution walk.
+pub fn subst(generic: &Generic) -> Arc<dyn Type> {
+    let mapping: HashMap<TypeVarId, Arc<dyn Type>> = generic
+        .base
+        .vars
+        .iter()
+        .copied()
+        .zip(generic.params.iter().cloned())
+        .collect();
+    substitute(&generic.base.body, &mapping)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{unify::Unifier, Primitive, Trait};
+
+    #[test]
+    fn covariant_container_follows_param_subtyping() {
+        let animal = Trait::new_arc(vec![]);
+        let cat = Trait::new_arc(vec![&animal]);
+
+        #[allow(clippy::arc_with_non_send_sync)]
+        let box_def = Arc::new(GenericDef {
+            name: "Box".into(),
+            variances: vec![Variance::Covariant],
+            vars: vec![],
+            body: Primitive::Void.arc(),
+        });
+        let box_cat = Generic {
+            base: box_def.clone(),
+            params: vec![cat.clone()],
+        };
+        let box_animal = Generic {
+            base: box_def,
+            params: vec![animal.clone()],
+        };
+
+        assert!(box_cat.is_assignable_to(&box_animal));
+        assert!(!box_animal.is_assignable_to(&box_cat));
+    }
+
+    #[test]
+    fn contravariant_sink_reverses_param_subtyping() {
+        let animal = Trait::new_arc(vec![]);
+        let cat = Trait::new_arc(vec![&animal]);
+
+        #[allow(clippy::arc_with_non_send_sync)]
+        let consumer_def = Arc::new(GenericDef {
+            name: "Consumer".into(),
+            variances: vec![Variance::Contravariant],
+            vars: vec![],
+            body: Primitive::Void.arc(),
+        });
+        let consumer_animal = Generic {
+            base: consumer_def.clone(),
+            params: vec![animal.clone()],
+        };
+        let consumer_cat = Generic {
+            base: consumer_def,
+            params: vec![cat.clone()],
+        };
+
+        assert!(consumer_animal.is_assignable_to(&consumer_cat));
+        assert!(!consumer_cat.is_assignable_to(&consumer_animal));
+    }
+
+    #[test]
+    fn subst_substitutes_params_into_body() {
+        let mut u = Unifier::new();
+        let t = u.fresh_var();
+        let t_id = t.id();
+
+        #[allow(clippy::arc_with_non_send_sync)]
+        let box_def = Arc::new(GenericDef {
+            name: "Box".into(),
+            variances: vec![Variance::Covariant],
+            vars: vec![t_id],
+            body: t.arc(),
+        });
+        let boxed_int = Generic {
+            base: box_def,
+            params: vec![Primitive::Int(32).arc()],
+        };
+
+        let result = subst(&boxed_int);
+        assert!(result.as_ref().cast::<Primitive>().is_some());
+    }
+}
@@ -1,5 +1,10 @@
 use std::{any::Any, ops::Shl, ptr::eq, sync::Arc};
 
+pub mod coerce;
+pub mod generic;
+pub mod scheme;
+pub mod unify;
+
 pub trait IntoAny {
     fn into_any(&self) -> Box<&dyn Any>;
 }
@@ -15,6 +20,12 @@ pub trait Type: IntoAny {
     fn is_supertype_of(&self, _: Box<&dyn Type>) -> Option<bool> {
         None
     }
+
+    // A wrapper type (e.g. a box or reference) can expose the type it
+    // wraps, letting `coerce::can_coerce` retry coercion on that inner type.
+    fn deref_target(&self) -> Option<Arc<dyn Type>> {
+        None
+    }
 }
 
 pub trait BoxableType {
@@ -76,9 +87,17 @@ pub enum Primitive {
     Boolean,
     Int(usize),
     UInt(usize),
+    // Bottom type: a subtype of everything, used for diverging expressions.
+    Never,
+    // Top type: a supertype of everything.
+    Any,
 }
 impl Type for Primitive {
     fn is_assignable_to(&self, other: &dyn Type) -> bool {
+        // Never is assignable to any type, regardless of what `other` says.
+        if *self == Primitive::Never {
+            return true;
+        }
         if let Some(x) = other.is_supertype_of(self.boxed()) {
             return x;
         }
@@ -88,6 +107,16 @@ impl Type for Primitive {
             false
         }
     }
+
+    fn is_supertype_of(&self, other: Box<&dyn Type>) -> Option<bool> {
+        match self {
+            // Any is a supertype of every type.
+            Primitive::Any => Some(true),
+            // Never is a supertype only of itself.
+            Primitive::Never => Some(other.cast::<Primitive>() == Some(&Primitive::Never)),
+            _ => None,
+        }
+    }
 }
 
 pub struct Trait {
@@ -170,6 +199,43 @@ impl Type for Function {
     }
 }
 
+pub struct Struct {
+    pub fields: Vec<(Box<str>, Arc<dyn Type>)>,
+}
+
+impl Struct {
+    pub fn new(fields: Vec<(&str, Arc<dyn Type>)>) -> Struct {
+        Struct {
+            fields: fields
+                .into_iter()
+                .map(|(name, field_type)| (name.into(), field_type))
+                .collect(),
+        }
+    }
+}
+
+impl Type for Struct {
+    fn is_assignable_to(&self, other: &dyn Type) -> bool {
+        if let Some(x) = other.is_supertype_of(self.boxed()) {
+            return x;
+        }
+        if let Some(x) = other.cast::<Struct>() {
+            // width subtyping: `other` may only require a subset of our fields;
+            // depth subtyping: each shared field is covariant
+            x.fields.iter().all(|(name, field_type)| {
+                self.fields
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .is_some_and(|(_, self_field_type)| {
+                        self_field_type.is_assignable_to(field_type.as_ref())
+                    })
+            })
+        } else {
+            false
+        }
+    }
+}
+
 #[test]
 fn primitive_type() {
     let bool_a = Primitive::Boolean.boxed();
@@ -245,3 +311,59 @@ fn function_type() {
     assert!(!trade_animal.is_assignable_to(&trade_cat));
     assert!(!trade_cat.is_assignable_to(&trade_animal));
 }
+
+#[test]
+fn never_and_any_types() {
+    let animal = Trait::new_arc(vec![]);
+    let cat = Trait::new_arc(vec![&animal]);
+    let never = Primitive::Never;
+    let any = Primitive::Any;
+
+    assert!(never.is_assignable_to(&Primitive::Void));
+    assert!(never.is_assignable_to(&Primitive::Boolean));
+    assert!(never.is_assignable_to(&Primitive::Int(32)));
+    assert!(Primitive::Void.is_assignable_to(&any));
+    assert!(Primitive::Boolean.is_assignable_to(&any));
+    assert!(Primitive::Int(32).is_assignable_to(&any));
+    assert!(animal.is_assignable_to(&any));
+    assert!(cat.is_assignable_to(&any));
+
+    assert!(never.is_assignable_to(&never));
+    assert!(!any.is_assignable_to(&never));
+    assert!(any.is_assignable_to(&any));
+
+    // return-type covariance: a function returning Never is assignable
+    // wherever any return type is expected
+    let returns_never = Function::new(vec![], Primitive::Never.arc());
+    let returns_int = Function::new(vec![], Primitive::Int(32).arc());
+    let returns_any = Function::new(vec![], Primitive::Any.arc());
+    assert!(returns_never.is_assignable_to(&returns_int));
+    assert!(returns_never.is_assignable_to(&returns_any));
+    assert!(!returns_int.is_assignable_to(&returns_never));
+}
+
+#[test]
+fn struct_type() {
+    let xy = Struct::new(vec![
+        ("x", Primitive::Int(32).arc()),
+        ("y", Primitive::Int(32).arc()),
+    ]);
+    let x = Struct::new(vec![("x", Primitive::Int(32).arc())]);
+    let z = Struct::new(vec![("z", Primitive::Int(32).arc())]);
+
+    // width subtyping: a record with extra fields is a subtype
+    assert!(xy.is_assignable_to(&x));
+    assert!(!x.is_assignable_to(&xy));
+    // field order must not matter
+    assert!(xy.is_assignable_to(&xy));
+    // missing required fields fail
+    assert!(!x.is_assignable_to(&z));
+
+    // depth subtyping: fields are covariant
+    let animal = Trait::new_arc(vec![]);
+    let cat = Trait::new_arc(vec![&animal]);
+    let has_cat = Struct::new(vec![("pet", cat.clone())]);
+    let has_animal = Struct::new(vec![("pet", animal.clone())]);
+    assert!(has_cat.is_assignable_to(&has_animal));
+    assert!(!has_animal.is_assignable_to(&has_cat));
+}
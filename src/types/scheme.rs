@@ -0,0 +1,164 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use super::{
+    unify::{TypeVar, TypeVarId, Unifier},
+    BoxableType, BoxedTypeUtils, Function, FunctionArgument, Type,
+};
+
+// A `forall vars. body` universally-quantified type.
+pub struct Scheme {
+    pub vars: Vec<TypeVarId>,
+    pub body: Arc<dyn Type>,
+}
+
+impl Scheme {
+    pub fn new(vars: Vec<TypeVarId>, body: Arc<dyn Type>) -> Scheme {
+        Scheme { vars, body }
+    }
+}
+
+// Produces a fresh copy of `scheme.body` with every quantified var replaced by
+// a brand-new unbound var, so each call site gets independent unknowns.
+pub fn instantiate(scheme: &Scheme, unifier: &mut Unifier) -> Arc<dyn Type> {
+    let mapping: HashMap<TypeVarId, Arc<dyn Type>> = scheme
+        .vars
+        .iter()
+        .map(|v| (*v, unifier.fresh_var().arc()))
+        .collect();
+    substitute(&scheme.body, &mapping)
+}
+
+// Walks `ty`, collecting every free var that does not appear in `env`, and
+// wraps them into a `Scheme` over `ty`.
+pub fn generalize(ty: &Arc<dyn Type>, env: &[TypeVarId]) -> Scheme {
+    let mut free = HashSet::new();
+    collect_free_vars(ty, &mut free);
+    for bound in env {
+        free.remove(bound);
+    }
+    Scheme::new(free.into_iter().collect(), ty.clone())
+}
+
+pub(crate) fn substitute(
+    ty: &Arc<dyn Type>,
+    mapping: &HashMap<TypeVarId, Arc<dyn Type>>,
+) -> Arc<dyn Type> {
+    if let Some(var) = ty.as_ref().cast::<TypeVar>() {
+        return mapping
+            .get(&var.id())
+            .cloned()
+            .unwrap_or_else(|| ty.clone());
+    }
+    if let Some(f) = ty.as_ref().cast::<Function>() {
+        #[allow(clippy::arc_with_non_send_sync)]
+        return Arc::new(Function {
+            args: f
+                .args
+                .iter()
+                .map(|a| FunctionArgument {
+                    name: a.name.clone(),
+                    arg_type: substitute(&a.arg_type, mapping),
+                })
+                .collect(),
+            return_type: substitute(&f.return_type, mapping),
+        });
+    }
+    ty.clone()
+}
+
+fn collect_free_vars(ty: &Arc<dyn Type>, out: &mut HashSet<TypeVarId>) {
+    if let Some(var) = ty.as_ref().cast::<TypeVar>() {
+        out.insert(var.id());
+        return;
+    }
+    if let Some(f) = ty.as_ref().cast::<Function>() {
+        for arg in &f.args {
+            collect_free_vars(&arg.arg_type, out);
+        }
+        collect_free_vars(&f.return_type, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Primitive;
+
+    #[test]
+    fn identity_scheme_instantiates_independently_per_call_site() {
+        let mut u = Unifier::new();
+        let a = u.fresh_var();
+        let a_id = a.id();
+
+        let identity = Scheme::new(
+            vec![a_id],
+            Function {
+                args: vec![FunctionArgument {
+                    name: "x".into(),
+                    arg_type: a.clone().arc(),
+                }],
+                return_type: a.arc(),
+            }
+            .arc(),
+        );
+
+        let call_site_int = instantiate(&identity, &mut u);
+        let call_site_bool = instantiate(&identity, &mut u);
+
+        let int_usage = Function {
+            args: vec![FunctionArgument {
+                name: "x".into(),
+                arg_type: Primitive::Int(32).arc(),
+            }],
+            return_type: Primitive::Int(32).arc(),
+        }
+        .arc();
+        let bool_usage = Function {
+            args: vec![FunctionArgument {
+                name: "x".into(),
+                arg_type: Primitive::Boolean.arc(),
+            }],
+            return_type: Primitive::Boolean.arc(),
+        }
+        .arc();
+
+        assert!(u.unify(&call_site_int, &int_usage).is_ok());
+        assert!(u.unify(&call_site_bool, &bool_usage).is_ok());
+        // the two instantiations were independent, so resolving one must not
+        // have leaked the other's binding
+        assert!(u
+            .resolve(&call_site_int)
+            .as_ref()
+            .cast::<Function>()
+            .is_some());
+        assert!(u
+            .resolve(&call_site_bool)
+            .as_ref()
+            .cast::<Function>()
+            .is_some());
+    }
+
+    #[test]
+    fn generalize_excludes_vars_bound_in_the_environment() {
+        let mut u = Unifier::new();
+        let a = u.fresh_var();
+        let b = u.fresh_var();
+        let a_id = a.id();
+        let b_id = b.id();
+
+        let ty = Function {
+            args: vec![FunctionArgument {
+                name: "x".into(),
+                arg_type: a.arc(),
+            }],
+            return_type: b.arc(),
+        }
+        .arc();
+
+        let scheme = generalize(&ty, &[a_id]);
+        assert_eq!(scheme.vars, vec![b_id]);
+    }
+}
@@ -0,0 +1,224 @@
+use std::{collections::HashMap, sync::Arc};
+
+use super::{BoxableType, BoxedTypeUtils, Function, FunctionArgument, Type};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TypeVarId(usize);
+
+#[derive(Clone)]
+pub struct TypeVar(TypeVarId);
+
+impl TypeVar {
+    pub fn id(&self) -> TypeVarId {
+        self.0
+    }
+}
+
+impl Type for TypeVar {
+    fn is_assignable_to(&self, other: &dyn Type) -> bool {
+        if let Some(x) = other.is_supertype_of(self.boxed()) {
+            return x;
+        }
+        other.cast::<TypeVar>().is_some_and(|x| self.0 == x.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UnifyError {
+    Occurs(TypeVarId),
+    Mismatch,
+}
+
+enum Binding {
+    Unbound,
+    Bound(Arc<dyn Type>),
+}
+
+pub struct Unifier {
+    table: HashMap<TypeVarId, Binding>,
+    next_id: usize,
+}
+
+impl Unifier {
+    pub fn new() -> Unifier {
+        Unifier {
+            table: HashMap::new(),
+            next_id: 0,
+        }
+    }
+
+    pub fn fresh_var(&mut self) -> TypeVar {
+        let id = TypeVarId(self.next_id);
+        self.next_id += 1;
+        self.table.insert(id, Binding::Unbound);
+        TypeVar(id)
+    }
+
+    pub fn unify(&mut self, a: &Arc<dyn Type>, b: &Arc<dyn Type>) -> Result<(), UnifyError> {
+        let a = self.shallow_resolve(a);
+        let b = self.shallow_resolve(b);
+
+        let a_var = a.as_ref().cast::<TypeVar>().map(TypeVar::id);
+        let b_var = b.as_ref().cast::<TypeVar>().map(TypeVar::id);
+
+        match (a_var, b_var) {
+            (Some(x), Some(y)) if x == y => Ok(()),
+            (Some(x), _) => self.bind(x, &b),
+            (_, Some(y)) => self.bind(y, &a),
+            (None, None) => self.unify_concrete(&a, &b),
+        }
+    }
+
+    // Unifies two vars-free-at-the-top types, recursing into `Function` so that
+    // vars nested in its args/return get unified rather than compared for assignability.
+    fn unify_concrete(&mut self, a: &Arc<dyn Type>, b: &Arc<dyn Type>) -> Result<(), UnifyError> {
+        if let (Some(fa), Some(fb)) = (a.as_ref().cast::<Function>(), b.as_ref().cast::<Function>())
+        {
+            if fa.args.len() != fb.args.len() {
+                return Err(UnifyError::Mismatch);
+            }
+            for (arg_a, arg_b) in fa.args.iter().zip(fb.args.iter()) {
+                self.unify(&arg_a.arg_type, &arg_b.arg_type)?;
+            }
+            return self.unify(&fa.return_type, &fb.return_type);
+        }
+
+        if a.is_assignable_to(b.as_ref()) || b.is_assignable_to(a.as_ref()) {
+            Ok(())
+        } else {
+            Err(UnifyError::Mismatch)
+        }
+    }
+
+    // Fully substitutes `ty`, replacing every bound var with its resolved value.
+    pub fn resolve(&self, ty: &Arc<dyn Type>) -> Arc<dyn Type> {
+        let ty = self.shallow_resolve(ty);
+        if let Some(f) = ty.as_ref().cast::<Function>() {
+            #[allow(clippy::arc_with_non_send_sync)]
+            return Arc::new(Function {
+                args: f
+                    .args
+                    .iter()
+                    .map(|a| FunctionArgument {
+                        name: a.name.clone(),
+                        arg_type: self.resolve(&a.arg_type),
+                    })
+                    .collect(),
+                return_type: self.resolve(&f.return_type),
+            });
+        }
+        ty
+    }
+
+    // Resolves only the outermost layer: a bound var becomes its binding, once.
+    fn shallow_resolve(&self, ty: &Arc<dyn Type>) -> Arc<dyn Type> {
+        if let Some(var) = ty.as_ref().cast::<TypeVar>() {
+            if let Some(Binding::Bound(bound)) = self.table.get(&var.id()) {
+                return self.shallow_resolve(bound);
+            }
+        }
+        ty.clone()
+    }
+
+    fn bind(&mut self, var: TypeVarId, ty: &Arc<dyn Type>) -> Result<(), UnifyError> {
+        if self.occurs(var, ty) {
+            return Err(UnifyError::Occurs(var));
+        }
+        self.table.insert(var, Binding::Bound(ty.clone()));
+        Ok(())
+    }
+
+    fn occurs(&self, var: TypeVarId, ty: &Arc<dyn Type>) -> bool {
+        let ty = self.shallow_resolve(ty);
+        if let Some(v) = ty.as_ref().cast::<TypeVar>() {
+            return v.id() == var;
+        }
+        if let Some(f) = ty.as_ref().cast::<Function>() {
+            return self.occurs(var, &f.return_type)
+                || f.args.iter().any(|a| self.occurs(var, &a.arg_type));
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Primitive, Trait};
+
+    #[test]
+    fn unify_var_with_concrete() {
+        let mut u = Unifier::new();
+        let v = u.fresh_var().arc();
+        let int32 = Primitive::Int(32).arc();
+
+        assert!(u.unify(&v, &int32).is_ok());
+        assert!(u.resolve(&v).as_ref().cast::<Primitive>().is_some());
+    }
+
+    #[test]
+    fn unify_two_concrete_types() {
+        let mut u = Unifier::new();
+        let animal = Trait::new_arc(vec![]);
+        let cat = Trait::new_arc(vec![&animal]);
+
+        assert!(u
+            .unify(
+                &(cat.clone() as Arc<dyn Type>),
+                &(animal.clone() as Arc<dyn Type>)
+            )
+            .is_ok());
+        assert_eq!(
+            u.unify(&(animal.clone() as Arc<dyn Type>), &(Primitive::Void.arc())),
+            Err(UnifyError::Mismatch)
+        );
+    }
+
+    #[test]
+    fn unify_function_args_and_return() {
+        let mut u = Unifier::new();
+        let v_arg = u.fresh_var().arc();
+        let v_ret = u.fresh_var().arc();
+
+        let generic_id = Function {
+            args: vec![FunctionArgument {
+                name: "x".into(),
+                arg_type: v_arg.clone(),
+            }],
+            return_type: v_ret.clone(),
+        }
+        .arc();
+
+        let concrete_id = Function {
+            args: vec![FunctionArgument {
+                name: "x".into(),
+                arg_type: Primitive::Int(32).arc(),
+            }],
+            return_type: Primitive::Int(32).arc(),
+        }
+        .arc();
+
+        assert!(u.unify(&generic_id, &concrete_id).is_ok());
+        assert!(u.resolve(&v_arg).as_ref().cast::<Primitive>().is_some());
+        assert!(u.resolve(&v_ret).as_ref().cast::<Primitive>().is_some());
+    }
+
+    #[test]
+    fn occurs_check_rejects_infinite_type() {
+        let mut u = Unifier::new();
+        let v = u.fresh_var();
+        let v_id = v.id();
+        let v_arc = v.arc();
+
+        let self_referential = Function {
+            args: vec![],
+            return_type: v_arc.clone(),
+        }
+        .arc();
+
+        assert_eq!(
+            u.unify(&v_arc, &self_referential),
+            Err(UnifyError::Occurs(v_id))
+        );
+    }
+}